@@ -1,7 +1,8 @@
 use nu_protocol::ast::{Call, PathMember};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Value,
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, SyntaxShape, Value,
 };
 
 #[derive(Clone)]
@@ -13,7 +14,37 @@ impl Command for ToJson {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("to json").category(Category::Formats)
+        Signature::build("to json")
+            .switch(
+                "ndjson",
+                "output newline-delimited JSON, one compact object per input row",
+                None,
+            )
+            .switch("raw", "remove all of the whitespace", Some('r'))
+            .switch(
+                "sort-keys",
+                "sort record keys alphabetically for stable, diff-friendly output",
+                None,
+            )
+            .named(
+                "indent",
+                SyntaxShape::Int,
+                "specify indentation width (default: 2)",
+                None,
+            )
+            .named(
+                "tabs",
+                SyntaxShape::Int,
+                "specify indentation tab quantity, using tabs instead of spaces",
+                None,
+            )
+            .named(
+                "timestamp",
+                SyntaxShape::String,
+                "serialize dates as \"rfc3339\" (default) or \"epoch\" seconds; does not affect duration/filesize",
+                None,
+            )
+            .category(Category::Formats)
     }
 
     fn usage(&self) -> &str {
@@ -22,31 +53,75 @@ impl Command for ToJson {
 
     fn run(
         &self,
-        _engine_state: &EngineState,
-        _stack: &mut Stack,
+        engine_state: &EngineState,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<nu_protocol::PipelineData, ShellError> {
-        to_json(call, input)
+        to_json(engine_state, stack, call, input)
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description:
-                "Outputs an unformatted JSON string representing the contents of this table",
-            example: "[1 2 3] | to json",
-            result: Some(Value::test_string("[\n  1,\n  2,\n  3\n]")),
-        }]
+        vec![
+            Example {
+                description:
+                    "Outputs a JSON string, with default indentation, representing the contents of this table",
+                example: "[1 2 3] | to json",
+                result: Some(Value::test_string("[\n  1,\n  2,\n  3\n]")),
+            },
+            Example {
+                description: "Outputs newline-delimited JSON as a stream of strings, one compact object per row",
+                example: "[{a: 1} {a: 2}] | to json --ndjson",
+                // Streamed row-by-row, so the result isn't a single string to assert against here.
+                result: None,
+            },
+            Example {
+                description: "Outputs a compact JSON string (no whitespace)",
+                example: "[1 2 3] | to json --raw",
+                result: Some(Value::test_string("[1,2,3]")),
+            },
+            Example {
+                description: "Outputs record keys in sorted order for a stable, diffable result",
+                example: "{b: 2, a: 1} | to json --raw --sort-keys",
+                result: Some(Value::test_string("{\"a\":1,\"b\":2}")),
+            },
+            Example {
+                description: "Serializes dates as Unix epoch seconds instead of RFC 3339 strings",
+                example: "{ts: (date now)} | to json --timestamp epoch",
+                result: None,
+            },
+        ]
     }
 }
 
-pub fn value_to_json_value(v: &Value) -> Result<nu_json::Value, ShellError> {
+pub fn value_to_json_value(
+    v: &Value,
+    sort_keys: bool,
+    epoch_timestamps: bool,
+) -> Result<nu_json::Value, ShellError> {
     Ok(match v {
         Value::Bool { val, .. } => nu_json::Value::Bool(*val),
+        // `--timestamp` only disambiguates `Date`; `Filesize`/`Duration` stay raw bytes/nanoseconds
+        // here, so callers still need to divide/format them manually on the way out.
         Value::Filesize { val, .. } => nu_json::Value::I64(*val),
         Value::Duration { val, .. } => nu_json::Value::I64(*val),
-        Value::Date { val, .. } => nu_json::Value::String(val.to_string()),
-        Value::Float { val, .. } => nu_json::Value::F64(*val),
+        Value::Date { val, .. } => {
+            if epoch_timestamps {
+                nu_json::Value::I64(val.timestamp())
+            } else {
+                nu_json::Value::String(val.to_rfc3339())
+            }
+        }
+        // NaN/Infinity have no JSON representation; null is the conventional fallback. Handled
+        // here (rather than in the string writer) so it still applies now that rendering is
+        // delegated to `nu_json::to_string`/`to_string_raw`.
+        Value::Float { val, .. } => {
+            if val.is_finite() {
+                nu_json::Value::F64(*val)
+            } else {
+                nu_json::Value::Null
+            }
+        }
         Value::Int { val, .. } => nu_json::Value::I64(*val),
         Value::Nothing { .. } => nu_json::Value::Null,
         Value::String { val, .. } => nu_json::Value::String(val.to_string()),
@@ -60,7 +135,9 @@ pub fn value_to_json_value(v: &Value) -> Result<nu_json::Value, ShellError> {
                 .collect::<Result<Vec<nu_json::Value>, ShellError>>()?,
         ),
 
-        Value::List { vals, .. } => nu_json::Value::Array(json_list(vals)?),
+        Value::List { vals, .. } => {
+            nu_json::Value::Array(json_list(vals, sort_keys, epoch_timestamps)?)
+        }
         Value::Error { error } => return Err(error.clone()),
         Value::Block { .. } | Value::Range { .. } => nu_json::Value::Null,
         Value::Binary { val, .. } => {
@@ -68,8 +145,16 @@ pub fn value_to_json_value(v: &Value) -> Result<nu_json::Value, ShellError> {
         }
         Value::Record { cols, vals, .. } => {
             let mut m = nu_json::Map::new();
-            for (k, v) in cols.iter().zip(vals) {
-                m.insert(k.clone(), value_to_json_value(v)?);
+            if sort_keys {
+                let mut entries: Vec<(&String, &Value)> = cols.iter().zip(vals).collect();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                for (k, v) in entries {
+                    m.insert(k.clone(), value_to_json_value(v, sort_keys, epoch_timestamps)?);
+                }
+            } else {
+                for (k, v) in cols.iter().zip(vals) {
+                    m.insert(k.clone(), value_to_json_value(v, sort_keys, epoch_timestamps)?);
+                }
             }
             nu_json::Value::Object(m)
         }
@@ -77,33 +162,197 @@ pub fn value_to_json_value(v: &Value) -> Result<nu_json::Value, ShellError> {
     })
 }
 
-fn json_list(input: &[Value]) -> Result<Vec<nu_json::Value>, ShellError> {
+fn json_list(
+    input: &[Value],
+    sort_keys: bool,
+    epoch_timestamps: bool,
+) -> Result<Vec<nu_json::Value>, ShellError> {
     let mut out = vec![];
 
     for value in input {
-        out.push(value_to_json_value(value)?);
+        out.push(value_to_json_value(value, sort_keys, epoch_timestamps)?);
     }
 
     Ok(out)
 }
 
-fn to_json(call: &Call, input: PipelineData) -> Result<PipelineData, ShellError> {
+/// Render a `nu_json::Value` as a JSON string. The default (no flags) and `--raw` cases are
+/// handled entirely by `nu_json`, which already escapes strings/keys and formats numbers
+/// correctly; only a caller-supplied `custom_indent` unit (from `--indent`/`--tabs`) falls back
+/// to re-flowing `nu_json`'s own compact output, since `nu_json::to_string` hard-codes its
+/// indentation width.
+fn json_value_to_string(
+    value: &nu_json::Value,
+    raw: bool,
+    custom_indent: Option<&str>,
+    type_name: &str,
+    span: Span,
+) -> Result<String, ShellError> {
+    let convert_err = || ShellError::CantConvert("JSON".into(), type_name.into(), span);
+
+    if raw {
+        return nu_json::to_string_raw(value).map_err(|_| convert_err());
+    }
+
+    match custom_indent {
+        None => nu_json::to_string(value).map_err(|_| convert_err()),
+        Some(indent_unit) => {
+            let compact = nu_json::to_string_raw(value).map_err(|_| convert_err())?;
+            Ok(reindent_compact_json(&compact, indent_unit))
+        }
+    }
+}
+
+/// Re-flow an already-compact, correctly escaped JSON string using `indent_unit` for each
+/// nesting level. Only whitespace at structural boundaries (around `{}`, `[]`, `,`, `:`) is
+/// touched; string contents are copied through untouched. Backslash escapes are only tracked
+/// to find where each string ends; they are never decoded or re-encoded.
+fn reindent_compact_json(compact: &str, indent_unit: &str) -> String {
+    let mut out = String::with_capacity(compact.len() * 2);
+    let mut depth = 0usize;
+    let mut chars = compact.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            match c {
+                '\\' => {
+                    if let Some(escaped) = chars.next() {
+                        out.push(escaped);
+                    }
+                }
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_string = true;
+                out.push(c);
+            }
+            '{' | '[' => {
+                let close = if c == '{' { '}' } else { ']' };
+                out.push(c);
+                if chars.peek() == Some(&close) {
+                    out.push(chars.next().expect("peeked"));
+                } else {
+                    depth += 1;
+                    out.push('\n');
+                    out.push_str(&indent_unit.repeat(depth));
+                }
+            }
+            '}' | ']' => {
+                depth = depth.saturating_sub(1);
+                out.push('\n');
+                out.push_str(&indent_unit.repeat(depth));
+                out.push(c);
+            }
+            ',' => {
+                out.push(c);
+                out.push('\n');
+                out.push_str(&indent_unit.repeat(depth));
+            }
+            ':' => {
+                out.push(c);
+                out.push(' ');
+            }
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+fn to_json(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
     let span = call.head;
 
-    let value = input.into_value(span);
+    let raw = call.has_flag("raw");
+    let sort_keys = call.has_flag("sort-keys");
+    let indent = call.get_flag::<i64>(engine_state, stack, "indent")?;
+    let tabs = call.get_flag::<i64>(engine_state, stack, "tabs")?;
+    let timestamp_mode = call
+        .get_flag::<String>(engine_state, stack, "timestamp")?
+        .unwrap_or_else(|| "rfc3339".into());
+    let epoch_timestamps = match timestamp_mode.as_str() {
+        "rfc3339" => false,
+        "epoch" => true,
+        _ => {
+            return Err(ShellError::IncompatibleParametersSingle(
+                "`--timestamp` must be either \"rfc3339\" or \"epoch\"".into(),
+                span,
+            ))
+        }
+    };
+    const MAX_INDENT_WIDTH: i64 = 16;
+    let ndjson = call.has_flag("ndjson");
 
-    let json_value = value_to_json_value(&value)?;
-    match nu_json::to_string(&json_value) {
-        Ok(serde_json_string) => Ok(Value::String {
-            val: serde_json_string,
-            span,
+    // NDJSON always serializes compact, ignoring `--raw`/`--indent`/`--tabs` entirely, so the
+    // conflict checks below only make sense outside of `--ndjson`.
+    if !ndjson {
+        if indent.is_some() && tabs.is_some() {
+            return Err(ShellError::IncompatibleParametersSingle(
+                "`--indent` and `--tabs` cannot be used together".into(),
+                span,
+            ));
         }
-        .into_pipeline_data()),
-        _ => Ok(Value::Error {
-            error: ShellError::CantConvert("JSON".into(), value.get_type().to_string(), span),
+        if raw && (indent.is_some() || tabs.is_some()) {
+            return Err(ShellError::IncompatibleParametersSingle(
+                "`--raw` cannot be used together with `--indent`/`--tabs`".into(),
+                span,
+            ));
         }
-        .into_pipeline_data()),
     }
+
+    let custom_indent = match (indent, tabs) {
+        (_, Some(width)) => Some("\t".repeat(width.clamp(0, MAX_INDENT_WIDTH) as usize)),
+        (Some(width), None) => Some(" ".repeat(width.clamp(0, MAX_INDENT_WIDTH) as usize)),
+        (None, None) => None,
+    };
+
+    if ndjson {
+        let ctrlc = engine_state.ctrlc.clone();
+        // NDJSON is always compact (one object per line), regardless of `--raw`, and every
+        // line is newline-terminated so consecutive `save --append` runs never merge onto
+        // the same physical line.
+        let lines = input.into_iter().map(move |value| {
+            let span = value.span().unwrap_or(span);
+            let type_name = value.get_type().to_string();
+            match value_to_json_value(&value, sort_keys, epoch_timestamps) {
+                Ok(json_value) => match nu_json::to_string_raw(&json_value) {
+                    Ok(mut line) => {
+                        line.push('\n');
+                        Value::String { val: line, span }
+                    }
+                    Err(_) => Value::Error {
+                        error: ShellError::CantConvert("JSON".into(), type_name, span),
+                    },
+                },
+                Err(error) => Value::Error { error },
+            }
+        });
+
+        return Ok(lines.into_pipeline_data(ctrlc));
+    }
+
+    let value = input.into_value(span);
+    let type_name = value.get_type().to_string();
+
+    let json_value = value_to_json_value(&value, sort_keys, epoch_timestamps)?;
+    let json_string =
+        json_value_to_string(&json_value, raw, custom_indent.as_deref(), &type_name, span)?;
+    Ok(Value::String {
+        val: json_string,
+        span,
+    }
+    .into_pipeline_data())
 }
 
 #[cfg(test)]